@@ -3,6 +3,123 @@ use rand::Rng;
 use std::fmt;
 use wasm_bindgen::prelude::*;
 
+/// The continuous time scale a `GeoTemporalUuid`'s 48-bit timestamp field is
+/// measured in.
+///
+/// Plain UTC repeats or skips a second around a leap-second insertion, which
+/// makes the stored field non-monotonic and ambiguous right at the boundary.
+/// `Tai` and `Gps` are leap-second-free scales derived from UTC via the
+/// built-in [`leap_seconds_for_utc`] table, so IDs generated on either of
+/// them sort correctly and decode to an unambiguous instant across leap
+/// seconds. The scale is a property of how a given caller chooses to encode
+/// and decode, not something recorded in the ID itself — callers must decode
+/// with the same scale they encoded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeScale {
+    #[default]
+    Utc,
+    Tai,
+    Gps,
+}
+
+impl std::str::FromStr for TimeScale {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "utc" => Ok(TimeScale::Utc),
+            "tai" => Ok(TimeScale::Tai),
+            "gps" => Ok(TimeScale::Gps),
+            other => Err(format!("Unknown time scale: {other} (expected utc, tai, or gps)")),
+        }
+    }
+}
+
+/// Unix-epoch seconds (UTC) at which each new TAI-UTC offset took effect,
+/// paired with the cumulative offset (in seconds) from that point on.
+/// Sourced from the IERS Bulletin C leap second table; no leap second has
+/// been inserted since 2016-12-31 (offset 37), per the 2022 CGPM leap-second
+/// freeze.
+const LEAP_SECONDS_UTC: &[(i64, i64)] = &[
+    (63072000, 10),   // 1972-01-01
+    (78796800, 11),   // 1972-07-01
+    (94694400, 12),   // 1973-01-01
+    (126230400, 13),  // 1974-01-01
+    (157766400, 14),  // 1975-01-01
+    (189302400, 15),  // 1976-01-01
+    (220924800, 16),  // 1977-01-01
+    (252460800, 17),  // 1978-01-01
+    (283996800, 18),  // 1979-01-01
+    (315532800, 19),  // 1980-01-01
+    (362793600, 20),  // 1981-07-01
+    (394329600, 21),  // 1982-07-01
+    (425865600, 22),  // 1983-07-01
+    (489024000, 23),  // 1985-07-01
+    (567993600, 24),  // 1988-01-01
+    (631152000, 25),  // 1990-01-01
+    (662688000, 26),  // 1991-01-01
+    (709948800, 27),  // 1992-07-01
+    (741484800, 28),  // 1993-07-01
+    (773020800, 29),  // 1994-07-01
+    (820454400, 30),  // 1996-01-01
+    (867715200, 31),  // 1997-07-01
+    (915148800, 32),  // 1999-01-01
+    (1136073600, 33), // 2006-01-01
+    (1230768000, 34), // 2009-01-01
+    (1341100800, 35), // 2012-07-01
+    (1435708800, 36), // 2015-07-01
+    (1483228800, 37), // 2017-01-01
+];
+
+/// GPS time is offset from TAI by a fixed 19 seconds (GPS epoch 1980-01-06,
+/// which was already 19 leap seconds behind TAI and has stayed fixed since,
+/// because GPS time does not observe leap seconds).
+const GPS_TAI_OFFSET_SECS: i64 = 19;
+
+/// Looks up the cumulative TAI-UTC offset (seconds) in effect at a given UTC instant.
+fn leap_seconds_for_utc(utc_secs: i64) -> i64 {
+    LEAP_SECONDS_UTC
+        .iter()
+        .rev()
+        .find(|(threshold, _)| utc_secs >= *threshold)
+        .map(|(_, offset)| *offset)
+        .unwrap_or(0)
+}
+
+/// Looks up the cumulative TAI-UTC offset (seconds) in effect at a given TAI instant,
+/// i.e. the inverse lookup of [`leap_seconds_for_utc`].
+fn leap_seconds_for_tai(tai_secs: i64) -> i64 {
+    LEAP_SECONDS_UTC
+        .iter()
+        .rev()
+        .find(|(threshold, offset)| tai_secs >= *threshold + *offset)
+        .map(|(_, offset)| *offset)
+        .unwrap_or(0)
+}
+
+/// Converts a UTC millisecond timestamp to the given continuous scale.
+fn utc_ms_to_scale(utc_ms: u64, scale: TimeScale) -> u64 {
+    match scale {
+        TimeScale::Utc => utc_ms,
+        TimeScale::Tai => utc_ms + (leap_seconds_for_utc(utc_ms as i64 / 1000) * 1000) as u64,
+        TimeScale::Gps => {
+            let tai_ms = utc_ms_to_scale(utc_ms, TimeScale::Tai);
+            tai_ms - (GPS_TAI_OFFSET_SECS * 1000) as u64
+        }
+    }
+}
+
+/// Converts a millisecond timestamp in the given continuous scale back to UTC.
+fn scale_ms_to_utc(scale_ms: u64, scale: TimeScale) -> u64 {
+    match scale {
+        TimeScale::Utc => scale_ms,
+        TimeScale::Tai => scale_ms - (leap_seconds_for_tai(scale_ms as i64 / 1000) * 1000) as u64,
+        TimeScale::Gps => {
+            let tai_ms = scale_ms + (GPS_TAI_OFFSET_SECS * 1000) as u64;
+            scale_ms_to_utc(tai_ms, TimeScale::Tai)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GeoTemporalUuid([u8; 16]);
 
@@ -14,7 +131,37 @@ impl GeoTemporalUuid {
     /// Random (25 bits)
     const RAND_BITS: u64 = 25;
 
-    pub fn new(lat: f64, lon: f64, time: Option<DateTime<Utc>>) -> Result<Self, &'static str> {
+    /// Altitude (18 bits), 3D variant only
+    const ALT_BITS: u64 = 18;
+    /// Random (7 bits), 3D variant only — the payload budget is fixed at 122
+    /// bits (48 time + 25 lon + 24 lat + 18 alt + 7 rand = 122), so adding
+    /// `ALT_BITS` for altitude comes out of the random field.
+    const RAND_BITS_3D: u64 = 7;
+
+    /// Altitude range covered by the 3D variant's quantized field.
+    const ALT_MIN_M: f64 = -500.0;
+    const ALT_MAX_M: f64 = 100_000.0;
+
+    pub fn new(
+        lat: f64,
+        lon: f64,
+        time: Option<DateTime<Utc>>,
+        scale: TimeScale,
+    ) -> Result<Self, &'static str> {
+        let utc = time.unwrap_or_else(Utc::now);
+        let utc_ms = utc.timestamp_millis() as u64;
+        let ts_ms = utc_ms_to_scale(utc_ms, scale) & 0xFFFF_FFFF_FFFF; // 48 bits
+
+        let mut rng = rand::rng();
+        let rnd = rng.random_range(0..(1 << Self::RAND_BITS));
+
+        Self::encode(lat, lon, ts_ms, rnd)
+    }
+
+    /// Packs a pre-computed 48-bit timestamp and 25-bit random field, bypassing
+    /// `new()`'s own clock read and randomness draw. Shared by `new()` and
+    /// [`MonotonicGenerator`], which needs to supply its own incremented random value.
+    fn encode(lat: f64, lon: f64, ts_ms: u64, rnd: u32) -> Result<Self, &'static str> {
         if lat < -90.0 || lat > 90.0 {
             return Err("Latitude must be between -90 and 90");
         }
@@ -22,10 +169,6 @@ impl GeoTemporalUuid {
             return Err("Longitude must be between -180 and 180");
         }
 
-        // 1. Prepare Data
-        let utc = time.unwrap_or_else(Utc::now);
-        let ts_ms = (utc.timestamp_millis() as u64) & 0xFFFF_FFFF_FFFF; // 48 bits
-
         // Normalize Lat (24 bits)
         let lat_normalized = (lat + 90.0) / 180.0;
         let lat_int = (lat_normalized * ((1 << Self::LAT_BITS) as f64 - 1.0)).round() as u32;
@@ -34,10 +177,6 @@ impl GeoTemporalUuid {
         let lon_normalized = (lon + 180.0) / 360.0;
         let lon_int = (lon_normalized * ((1 << Self::LON_BITS) as f64 - 1.0)).round() as u32;
 
-        // Random (25 bits)
-        let mut rng = rand::rng();
-        let rnd = rng.random_range(0..(1 << Self::RAND_BITS));
-
         // 2. Interleave Bits (Pure Z-Curve / Morton at top level)
         // Sources: Time(48), Lon(25), Lat(24).
         // Strategy: Round-robin MSB Interleaving (T, O, L).
@@ -45,9 +184,7 @@ impl GeoTemporalUuid {
         // Followed by 25 bits of Random.
         // Total Payload = 122 bits.
 
-        let mut uuid_u128: u128 = 0;
-        
-        // Re-approach: Flatten sources into a single 122-bit buffer first.
+        // Flatten sources into a single 122-bit buffer first.
         let mut payload_bits = [false; 122]; 
         let mut pb_idx = 0;
         
@@ -94,13 +231,30 @@ impl GeoTemporalUuid {
             pb_idx += 1;
         }
 
-        // Now pack into UUID
+        Ok(GeoTemporalUuid(Self::pack_payload(&payload_bits, Self::VERSION_2D)))
+    }
+
+    /// Version nibble for the 2D (lat/lon) format.
+    const VERSION_2D: u8 = 0x7;
+    /// Version nibble for the 3D (lat/lon/alt) format. `8` is reserved by
+    /// RFC 4122bis for custom/vendor-specific formats, so it's free for our
+    /// own use as a sub-format flag without colliding with real UUIDv7s.
+    const VERSION_3D: u8 = 0x8;
+
+    /// Packs a flattened 122-bit payload plus a version nibble into the
+    /// final 128-bit value, setting the fixed RFC 4122 variant bits (`10`)
+    /// along the way. Shared by the 2D and 3D encoders.
+    fn pack_payload(payload_bits: &[bool; 122], version: u8) -> [u8; 16] {
+        let mut uuid_u128: u128 = 0;
         let mut p_cursor = 0;
         for p in (0..128).rev() {
             let abs_pos = 127 - p;
-            
+
             if (48..52).contains(&abs_pos) {
-                 if matches!(abs_pos, 49 | 50 | 51) { uuid_u128 |= 1 << p; }
+                let nibble_bit_idx = 3 - (abs_pos - 48);
+                if (version >> nibble_bit_idx) & 1 == 1 {
+                    uuid_u128 |= 1 << p;
+                }
             } else if (64..66).contains(&abs_pos) {
                  if matches!(abs_pos, 64) { uuid_u128 |= 1 << p; }
             } else {
@@ -111,7 +265,39 @@ impl GeoTemporalUuid {
             }
         }
 
-        Ok(GeoTemporalUuid(uuid_u128.to_be_bytes()))
+        uuid_u128.to_be_bytes()
+    }
+
+    /// Reads back the version nibble and the flattened 122-bit payload,
+    /// undoing [`pack_payload`](Self::pack_payload). Shared by the 2D and 3D decoders.
+    fn unpack_payload(&self) -> (u8, [bool; 122]) {
+        let uuid_u128 = u128::from_be_bytes(self.0);
+        let mut payload_bits = [false; 122];
+        let mut p_cursor = 0;
+        let mut version: u8 = 0;
+
+        for p in (0..128).rev() {
+            let abs_pos = 127 - p;
+            if (48..52).contains(&abs_pos) {
+                if (uuid_u128 >> p) & 1 == 1 {
+                    version |= 1 << (3 - (abs_pos - 48));
+                }
+                continue;
+            }
+            if (64..66).contains(&abs_pos) {
+                continue;
+            }
+            payload_bits[p_cursor] = (uuid_u128 >> p) & 1 == 1;
+            p_cursor += 1;
+        }
+
+        (version, payload_bits)
+    }
+
+    /// Whether this ID was minted by [`new_3d`](Self::new_3d) (carries an altitude field)
+    /// rather than [`new`](Self::new).
+    pub fn is_3d(&self) -> bool {
+        (self.0[6] >> 4) == Self::VERSION_3D
     }
 
     pub fn to_uuid_string(&self) -> String {
@@ -125,29 +311,15 @@ impl GeoTemporalUuid {
         )
     }
 
-    pub fn decode(&self) -> (f64, f64, DateTime<Utc>) {
-        let uuid_u128 = u128::from_be_bytes(self.0);
-        
+    /// Decodes assuming the timestamp field was encoded on `scale` (the same
+    /// scale passed to [`new`](Self::new) when this ID was minted).
+    pub fn decode(&self, scale: TimeScale) -> (f64, f64, DateTime<Utc>) {
+        let (_version, payload_bits) = self.unpack_payload();
+
         let mut ts_ms: u64 = 0;
         let mut lat_int: u32 = 0;
         let mut lon_int: u32 = 0;
-        
-        // Recover payload bits
-        let mut payload_bits = [false; 122];
-        let mut p_cursor = 0;
-        
-        // Walk the UUID bits exactly as in new() to extract payload stream
-        for p in (0..128).rev() {
-            let abs_pos = 127 - p;
-            if (48..52).contains(&abs_pos) || (64..66).contains(&abs_pos) {
-                 continue; 
-            }
-            
-            let bit = (uuid_u128 >> p) & 1 == 1;
-            payload_bits[p_cursor] = bit;
-            p_cursor += 1;
-        }
-        
+
         // De-interleave payload_bits -> ts, lat, lon
         // Logic must strictly mirror new().
         
@@ -182,13 +354,167 @@ impl GeoTemporalUuid {
         let lat = (lat_int as f64 / ((1 << Self::LAT_BITS) as f64 - 1.0)) * 180.0 - 90.0;
         let lon = (lon_int as f64 / ((1 << Self::LON_BITS) as f64 - 1.0)) * 360.0 - 180.0;
 
-        let seconds = (ts_ms / 1000) as i64;
-        let nsecs = ((ts_ms % 1000) * 1_000_000) as u32;
+        let utc_ms = scale_ms_to_utc(ts_ms, scale);
+        let seconds = (utc_ms / 1000) as i64;
+        let nsecs = ((utc_ms % 1000) * 1_000_000) as u32;
         let time = Utc.timestamp_opt(seconds, nsecs).unwrap();
 
         (lat, lon, time)
     }
-    
+
+    /// Like [`decode`](Self::decode), but localizes the timestamp to `tz`
+    /// instead of leaving it in UTC, formatted as RFC 3339 so the offset is
+    /// visible alongside the wall-clock time. Dispatches to
+    /// [`decode_3d`](Self::decode_3d) internally for a 3D ID, so the
+    /// localized time always matches what that ID actually decodes to
+    /// (altitude is dropped since this returns the same 2D-shaped tuple as
+    /// [`decode`](Self::decode)).
+    pub fn decode_in_tz(&self, tz: chrono_tz::Tz, scale: TimeScale) -> (f64, f64, String) {
+        let (lat, lon, utc) = if self.is_3d() {
+            let (lat, lon, _alt, utc) = self
+                .decode_3d(scale)
+                .expect("is_3d() is true, so decode_3d cannot return Err");
+            (lat, lon, utc)
+        } else {
+            self.decode(scale)
+        };
+        (lat, lon, utc.with_timezone(&tz).to_rfc3339())
+    }
+
+    /// Like [`new`](Self::new), but also captures altitude for tracks where
+    /// vertical position matters (aircraft, drones, satellites). The ID is
+    /// flagged as 3D via its version nibble so [`is_3d`](Self::is_3d) and
+    /// [`decode_3d`](Self::decode_3d) can tell it apart from a plain 2D ID.
+    pub fn new_3d(
+        lat: f64,
+        lon: f64,
+        alt_m: f64,
+        time: Option<DateTime<Utc>>,
+        scale: TimeScale,
+    ) -> Result<Self, &'static str> {
+        if lat < -90.0 || lat > 90.0 {
+            return Err("Latitude must be between -90 and 90");
+        }
+        if lon < -180.0 || lon > 180.0 {
+            return Err("Longitude must be between -180 and 180");
+        }
+        if alt_m < Self::ALT_MIN_M || alt_m > Self::ALT_MAX_M {
+            return Err("Altitude must be between -500 and 100000 meters");
+        }
+
+        let utc = time.unwrap_or_else(Utc::now);
+        let utc_ms = utc.timestamp_millis() as u64;
+        let ts_ms = utc_ms_to_scale(utc_ms, scale) & 0xFFFF_FFFF_FFFF;
+
+        let mut rng = rand::rng();
+        let rnd = rng.random_range(0..(1 << Self::RAND_BITS_3D));
+
+        let lat_normalized = (lat + 90.0) / 180.0;
+        let lat_int = (lat_normalized * ((1 << Self::LAT_BITS) as f64 - 1.0)).round() as u32;
+
+        let lon_normalized = (lon + 180.0) / 360.0;
+        let lon_int = (lon_normalized * ((1 << Self::LON_BITS) as f64 - 1.0)).round() as u32;
+
+        let alt_normalized = (alt_m - Self::ALT_MIN_M) / (Self::ALT_MAX_M - Self::ALT_MIN_M);
+        let alt_int = (alt_normalized * ((1 << Self::ALT_BITS) as f64 - 1.0)).round() as u32;
+
+        // Flatten T, O, L, A, R into the same 122-bit payload budget as the
+        // 2D format, round-robin interleaved (T, O, L, A) and MSB-aligned to
+        // the 48-bit time field exactly like `new()`, so nearby 3D positions
+        // still cluster in sort order.
+        let mut payload_bits = [false; 122];
+        let mut pb_idx = 0;
+        for i in (0..48).rev() {
+            payload_bits[pb_idx] = (ts_ms >> i) & 1 == 1;
+            pb_idx += 1;
+
+            let idx_o = i as isize - (48 - 25);
+            if idx_o >= 0 {
+                payload_bits[pb_idx] = (lon_int >> idx_o) & 1 == 1;
+                pb_idx += 1;
+            }
+
+            let idx_l = i as isize - (48 - 24);
+            if idx_l >= 0 {
+                payload_bits[pb_idx] = (lat_int >> idx_l) & 1 == 1;
+                pb_idx += 1;
+            }
+
+            let idx_a = i as isize - (48 - 18);
+            if idx_a >= 0 {
+                payload_bits[pb_idx] = (alt_int >> idx_a) & 1 == 1;
+                pb_idx += 1;
+            }
+        }
+
+        for i in (0..Self::RAND_BITS_3D).rev() {
+            payload_bits[pb_idx] = (rnd >> i) & 1 == 1;
+            pb_idx += 1;
+        }
+
+        Ok(GeoTemporalUuid(Self::pack_payload(&payload_bits, Self::VERSION_3D)))
+    }
+
+    /// Decodes a 3D ID produced by [`new_3d`](Self::new_3d). Returns an error
+    /// if this ID is actually a 2D one (see [`is_3d`](Self::is_3d)).
+    pub fn decode_3d(&self, scale: TimeScale) -> Result<(f64, f64, f64, DateTime<Utc>), &'static str> {
+        if !self.is_3d() {
+            return Err("Not a 3D GeoTemporalUuid");
+        }
+
+        let (_version, payload_bits) = self.unpack_payload();
+
+        let mut ts_ms: u64 = 0;
+        let mut lat_int: u32 = 0;
+        let mut lon_int: u32 = 0;
+        let mut alt_int: u32 = 0;
+
+        let mut pb_idx = 0;
+        for i in (0..48).rev() {
+            if payload_bits[pb_idx] {
+                ts_ms |= 1 << i;
+            }
+            pb_idx += 1;
+
+            let idx_o = i as isize - (48 - 25);
+            if idx_o >= 0 {
+                if payload_bits[pb_idx] {
+                    lon_int |= 1 << idx_o;
+                }
+                pb_idx += 1;
+            }
+
+            let idx_l = i as isize - (48 - 24);
+            if idx_l >= 0 {
+                if payload_bits[pb_idx] {
+                    lat_int |= 1 << idx_l;
+                }
+                pb_idx += 1;
+            }
+
+            let idx_a = i as isize - (48 - 18);
+            if idx_a >= 0 {
+                if payload_bits[pb_idx] {
+                    alt_int |= 1 << idx_a;
+                }
+                pb_idx += 1;
+            }
+        }
+
+        let lat = (lat_int as f64 / ((1 << Self::LAT_BITS) as f64 - 1.0)) * 180.0 - 90.0;
+        let lon = (lon_int as f64 / ((1 << Self::LON_BITS) as f64 - 1.0)) * 360.0 - 180.0;
+        let alt = (alt_int as f64 / ((1 << Self::ALT_BITS) as f64 - 1.0))
+            * (Self::ALT_MAX_M - Self::ALT_MIN_M)
+            + Self::ALT_MIN_M;
+
+        let utc_ms = scale_ms_to_utc(ts_ms, scale);
+        let seconds = (utc_ms / 1000) as i64;
+        let nsecs = ((utc_ms % 1000) * 1_000_000) as u32;
+        let time = Utc.timestamp_opt(seconds, nsecs).unwrap();
+
+        Ok((lat, lon, alt, time))
+    }
+
     pub fn as_bytes(&self) -> &[u8; 16] {
         &self.0
     }
@@ -196,6 +522,488 @@ impl GeoTemporalUuid {
     pub fn from_bytes(bytes: [u8; 16]) -> Self {
         GeoTemporalUuid(bytes)
     }
+
+    /// Converts to a standard `uuid::Uuid` for handing to libraries or
+    /// database columns typed as plain UUIDs.
+    ///
+    /// The bit layout already places a version nibble (7 for 2D, 8 for 3D)
+    /// and the RFC 4122 variant bits exactly where the `uuid` crate expects
+    /// them, so this is a plain byte reinterpretation.
+    pub fn to_uuid(&self) -> uuid::Uuid {
+        uuid::Uuid::from_bytes(self.0)
+    }
+
+    /// Converts from a standard `uuid::Uuid`, rejecting anything that isn't
+    /// one of ours: the version must read as 7 (2D) or 8 (3D) and the variant
+    /// must be RFC 4122, exactly what [`encode`](Self::encode) and
+    /// [`new_3d`](Self::new_3d) always produce. Because that's verified here,
+    /// `decode()`/`decode_3d()` are infallible-by-construction on any value
+    /// obtained through this path.
+    pub fn try_from_uuid(uuid: uuid::Uuid) -> Result<Self, &'static str> {
+        let version = uuid.get_version_num();
+        if version != 7 && version != Self::VERSION_3D as usize {
+            return Err("Not a GeoTemporalUuid: expected UUID version 7 or 8");
+        }
+        if uuid.get_variant() != uuid::Variant::RFC4122 {
+            return Err("Not a GeoTemporalUuid: expected the RFC 4122 variant");
+        }
+        Ok(GeoTemporalUuid(*uuid.as_bytes()))
+    }
+
+    /// Returns the four standard UUID fields, analogous to `Uuid::as_fields`.
+    pub fn fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        let (time_low, time_mid, time_hi_and_version, clock_seq_and_node) =
+            self.to_uuid().as_fields();
+        (time_low, time_mid, time_hi_and_version, *clock_seq_and_node)
+    }
+}
+
+/// Generates `GeoTemporalUuid`s that are strictly increasing even when minted
+/// repeatedly within the same millisecond, mirroring ULID's monotonic factory.
+///
+/// Ordinary [`GeoTemporalUuid::new`] draws a fresh 25-bit random field each
+/// call, so two IDs for the same timestamp and location are not guaranteed to
+/// sort in creation order. `MonotonicGenerator` instead remembers the last
+/// `(timestamp_ms, random)` pair it emitted and, when asked for one at the
+/// same or an earlier timestamp, reuses that timestamp and increments the
+/// stored random value. Because the random field occupies the
+/// least-significant tail of the interleaved payload, incrementing it
+/// preserves the overall byte ordering **as long as the location passed to
+/// each call stays the same** — lat/lon live in the interleaved prefix,
+/// which dominates the random tail, so a burst of calls from a moving
+/// source at the same millisecond is not guaranteed strictly increasing.
+#[derive(Debug, Default)]
+pub struct MonotonicGenerator {
+    last: Option<(u64, u32)>,
+}
+
+impl MonotonicGenerator {
+    pub fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Mints the next ID in the sequence, defaulting `time` to now.
+    ///
+    /// Returns an error if the 25-bit random field would overflow, which
+    /// happens only after generating more than 2^25 IDs at the same
+    /// (non-advancing) millisecond.
+    pub fn next(
+        &mut self,
+        lat: f64,
+        lon: f64,
+        time: Option<DateTime<Utc>>,
+        scale: TimeScale,
+    ) -> Result<GeoTemporalUuid, &'static str> {
+        let utc = time.unwrap_or_else(Utc::now);
+        let utc_ms = utc.timestamp_millis() as u64;
+        let ts_ms = utc_ms_to_scale(utc_ms, scale) & 0xFFFF_FFFF_FFFF;
+
+        let (ts_ms, rnd) = match self.last {
+            Some((last_ts, last_rnd)) if ts_ms <= last_ts => {
+                let next_rnd = last_rnd + 1;
+                if next_rnd >= (1 << GeoTemporalUuid::RAND_BITS) {
+                    return Err("Monotonic random field overflowed within the same millisecond");
+                }
+                (last_ts, next_rnd)
+            }
+            _ => {
+                let mut rng = rand::rng();
+                (ts_ms, rng.random_range(0..(1 << GeoTemporalUuid::RAND_BITS)))
+            }
+        };
+
+        let uuid = GeoTemporalUuid::encode(lat, lon, ts_ms, rnd)?;
+        self.last = Some((ts_ms, rnd));
+        Ok(uuid)
+    }
+}
+
+/// Crockford Base32 alphabet (ULID-style): excludes I, L, O, U to avoid
+/// visual confusion with 1, 1, 0, V.
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+impl GeoTemporalUuid {
+    /// Encode as the 26-character Crockford Base32 form used by ULIDs.
+    ///
+    /// The 128-bit value is treated as a big-endian integer and split into
+    /// one 3-bit leading symbol followed by twenty-five 5-bit symbols
+    /// (3 + 25*5 = 128), so the text form sorts identically to the raw bytes.
+    pub fn to_base32(&self) -> String {
+        let value = u128::from_be_bytes(self.0);
+        let mut out = [0u8; 26];
+        out[0] = CROCKFORD_ALPHABET[((value >> 125) & 0x07) as usize];
+        for (i, slot) in out.iter_mut().enumerate().skip(1) {
+            let shift = 125 - 5 * i;
+            *slot = CROCKFORD_ALPHABET[((value >> shift) & 0x1F) as usize];
+        }
+        // SAFETY: every byte comes from CROCKFORD_ALPHABET, which is ASCII.
+        String::from_utf8(out.to_vec()).unwrap()
+    }
+
+    /// Parse the 26-character Crockford Base32 form produced by [`to_base32`](Self::to_base32).
+    ///
+    /// Decoding is case-insensitive and maps `I`/`L` to `1` and `O` to `0`,
+    /// matching the common ULID convention for tolerating transcription errors.
+    pub fn from_base32(s: &str) -> Result<Self, String> {
+        if s.len() != 26 {
+            return Err("Base32 string must be exactly 26 characters".into());
+        }
+
+        let mut value: u128 = 0;
+        for (i, c) in s.chars().enumerate() {
+            let v = crockford_value(c).ok_or_else(|| format!("Invalid Base32 character: {c}"))?;
+            if i == 0 {
+                if v > 0x07 {
+                    return Err("Leading Base32 symbol would overflow 128 bits".into());
+                }
+                value |= (v as u128) << 125;
+            } else {
+                let shift = 125 - 5 * i;
+                value |= (v as u128) << shift;
+            }
+        }
+
+        Ok(GeoTemporalUuid(value.to_be_bytes()))
+    }
+}
+
+/// Maps a single Crockford Base32 character (case-insensitively) to its 5-bit value.
+fn crockford_value(c: char) -> Option<u8> {
+    match c.to_ascii_uppercase() {
+        'I' | 'L' => Some(1),
+        'O' => Some(0),
+        c => CROCKFORD_ALPHABET.iter().position(|&b| b == c as u8).map(|p| p as u8),
+    }
+}
+
+/// Which field a given bit of the interleaved T/O/L payload prefix belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dim {
+    Time,
+    Lon,
+    Lat,
+}
+
+/// Turns a spatial/temporal bounding box into the key-range scans needed to
+/// cover every `GeoTemporalUuid` inside it, taking advantage of the
+/// Morton-style interleaving that makes these IDs sort by location and time.
+///
+/// Because time, longitude, and latitude are interleaved MSB-first (the same
+/// round-robin order [`GeoTemporalUuid::new`] uses), a contiguous byte range
+/// corresponds to a contiguous region of the interleaved bit space. This
+/// recursively subdivides that space — at each bit, deciding whether the
+/// query box fully covers, partially overlaps, or misses the corresponding
+/// half of the active dimension — and emits a `[lo, hi]` range wherever a
+/// node is fully covered. Each dimension stops contributing splits as soon
+/// as it is fully covered, so the exact decomposition stays on the order of
+/// the bits needed to resolve the slowest dimension; if it still comes out
+/// above the caller's `max_ranges`, adjacent ranges are merged (always
+/// growing, never shrinking, coverage) until it fits.
+///
+/// **2D IDs only.** Every emitted range is built assuming the 2D (T, O, L)
+/// interleave and is packed with [`GeoTemporalUuid::VERSION_2D`]'s version
+/// nibble. A 3D ID (`is_3d()`, interleaved as T, O, L, A) sorts in a
+/// different part of the key space entirely and will never fall inside any
+/// of these ranges, even if its location and time are within the query box.
+/// If a table mixes 2D and 3D IDs, scanning only the ranges from this type
+/// will silently skip every 3D row in the box — query 3D ranges separately.
+pub struct RangeQuery;
+
+/// One bit of an in-progress `[lo, hi]` prefix: either fixed to a value, or
+/// left free because its dimension is already fully covered by the query (in
+/// which case both the 0- and 1-child span the same covered range, so there
+/// is no need to branch on it).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrefixBit {
+    Zero,
+    One,
+    Free,
+}
+
+impl RangeQuery {
+    /// The 97-bit interleaved prefix (Time, Lon, Lat) covers everything a
+    /// bounding box + time window can constrain; the trailing 25 (or, for
+    /// a 3D ID, 7) random bits never narrow a range and are always left free.
+    const PREFIX_BITS: usize = 97;
+
+    /// Absolute ceiling on the exact pre-merge pass's size, independent of
+    /// the caller's `max_ranges`. `collapse_to_budget`'s closest-pair merge
+    /// is quadratic in its input, so scaling the soft cap with an arbitrarily
+    /// large `max_ranges` (e.g. a bulk-export planner asking for 100,000)
+    /// would still let the exact pass — and then the merge — blow up; this
+    /// keeps both bounded no matter what the caller requests.
+    const SOFT_CAP_CEILING: usize = 4096;
+
+    /// Returns, in order, which dimension each of the 97 interleaved prefix
+    /// bits belongs to — the exact sequence `GeoTemporalUuid::new` fills
+    /// `payload_bits` in.
+    fn dimension_sequence() -> [Dim; Self::PREFIX_BITS] {
+        let mut seq = [Dim::Time; Self::PREFIX_BITS];
+        let mut idx = 0;
+        for i in (0..48).rev() {
+            seq[idx] = Dim::Time;
+            idx += 1;
+
+            let idx_o = i as isize - (48 - 25);
+            if idx_o >= 0 {
+                seq[idx] = Dim::Lon;
+                idx += 1;
+            }
+
+            let idx_l = i as isize - (48 - 24);
+            if idx_l >= 0 {
+                seq[idx] = Dim::Lat;
+                idx += 1;
+            }
+        }
+        seq
+    }
+
+    /// Quantizes a `[min, max]` query bound into an inclusive integer range
+    /// over `bits`, the same normalization [`GeoTemporalUuid::new`] uses.
+    fn quantize_range(min: f64, max: f64, domain_min: f64, domain_max: f64, bits: u32) -> (u32, u32) {
+        let scale = (1u32 << bits) as f64 - 1.0;
+        let to_int = |v: f64| -> u32 {
+            let normalized = (v - domain_min) / (domain_max - domain_min);
+            (normalized.clamp(0.0, 1.0) * scale).round() as u32
+        };
+        let lo = to_int(min.min(max));
+        let hi = to_int(min.max(max));
+        (lo, hi)
+    }
+
+    /// Computes a minimal set of `[lo, hi]` byte-range pairs covering every
+    /// `GeoTemporalUuid` whose position falls in `lat_range`/`lon_range` and
+    /// whose timestamp (on `scale`) falls in `time_range`.
+    ///
+    /// The decomposition stops subdividing a node — emitting one
+    /// over-covering range for its whole subtree instead — as soon as it has
+    /// collected a generous multiple of `max_ranges` leaves, so a
+    /// boundary-misaligned query over a huge region can't blow the exact
+    /// pass up to hundreds of thousands of tiny ranges. Each dimension also
+    /// stops contributing new splits as soon as the query fully covers it,
+    /// which is what keeps that exact pass on the order of the bits needed
+    /// by the slowest dimension in the first place. Whatever comes out of
+    /// that bounded pass is then merged — closest adjacent pair first, which
+    /// only ever widens a range, never narrows one — down to `max_ranges`.
+    pub fn ranges(
+        lat_range: (f64, f64),
+        lon_range: (f64, f64),
+        time_range: (DateTime<Utc>, DateTime<Utc>),
+        scale: TimeScale,
+        max_ranges: usize,
+    ) -> Vec<(GeoTemporalUuid, GeoTemporalUuid)> {
+        let (lat_lo, lat_hi) = Self::quantize_range(
+            lat_range.0, lat_range.1, -90.0, 90.0, GeoTemporalUuid::LAT_BITS as u32,
+        );
+        let (lon_lo, lon_hi) = Self::quantize_range(
+            lon_range.0, lon_range.1, -180.0, 180.0, GeoTemporalUuid::LON_BITS as u32,
+        );
+
+        let t0 = utc_ms_to_scale(time_range.0.timestamp_millis() as u64, scale);
+        let t1 = utc_ms_to_scale(time_range.1.timestamp_millis() as u64, scale);
+        let (t_lo, t_hi) = (t0.min(t1), t0.max(t1));
+
+        let dims = Self::dimension_sequence();
+        let mut out = Vec::new();
+        let mut prefix = [PrefixBit::Free; Self::PREFIX_BITS];
+
+        // A soft cap on the exact pass, generous enough to leave the later
+        // merge plenty of room to pick good boundaries, but small enough
+        // that the merge (quadratic in the number of leaves) stays cheap
+        // even for a caller-supplied `max_ranges` in the tens of thousands.
+        let soft_cap = max_ranges.saturating_mul(4).max(64).min(Self::SOFT_CAP_CEILING);
+
+        Self::recurse(
+            &dims, 0,
+            (0, 48, 0), (0, 25, 0), (0, 24, 0),
+            (t_lo, t_hi), (lon_lo as u64, lon_hi as u64), (lat_lo as u64, lat_hi as u64),
+            &mut prefix, &mut out, soft_cap,
+        );
+
+        out.sort_unstable();
+        Self::collapse_to_budget(out, max_ranges)
+    }
+
+    /// Merges the closest adjacent pair of ranges — the pair with the
+    /// smallest gap between them — repeatedly until at most `max_ranges`
+    /// remain. Merging only ever extends a range's `[lo, hi]` bounds to
+    /// include its neighbor, so the result always stays a superset of the
+    /// exact decomposition it started from.
+    fn collapse_to_budget(
+        mut ranges: Vec<(GeoTemporalUuid, GeoTemporalUuid)>,
+        max_ranges: usize,
+    ) -> Vec<(GeoTemporalUuid, GeoTemporalUuid)> {
+        let max_ranges = max_ranges.max(1);
+        while ranges.len() > max_ranges {
+            let gap = |a: &(GeoTemporalUuid, GeoTemporalUuid), b: &(GeoTemporalUuid, GeoTemporalUuid)| {
+                u128::from_be_bytes(*b.0.as_bytes()).saturating_sub(u128::from_be_bytes(*a.1.as_bytes()))
+            };
+            let (merge_at, _) = ranges
+                .windows(2)
+                .enumerate()
+                .map(|(i, pair)| (i, gap(&pair[0], &pair[1])))
+                .min_by_key(|&(_, g)| g)
+                .expect("ranges.len() > max_ranges >= 1 implies at least 2 entries");
+
+            let merged = (ranges[merge_at].0, ranges[merge_at + 1].1);
+            ranges.splice(merge_at..=merge_at + 1, [merged]);
+        }
+        ranges
+    }
+
+    /// Same as [`ranges`](Self::ranges), but returns each range as a pair of
+    /// Base32 prefixes rather than raw byte bounds, e.g. for a key-value
+    /// store whose range scans operate on the text form.
+    pub fn ranges_base32(
+        lat_range: (f64, f64),
+        lon_range: (f64, f64),
+        time_range: (DateTime<Utc>, DateTime<Utc>),
+        scale: TimeScale,
+        max_ranges: usize,
+    ) -> Vec<(String, String)> {
+        Self::ranges(lat_range, lon_range, time_range, scale, max_ranges)
+            .into_iter()
+            .map(|(lo, hi)| (lo.to_base32(), hi.to_base32()))
+            .collect()
+    }
+
+    /// `t`/`lon`/`lat` carry each dimension's `(prefix_value, bits_total,
+    /// bits_consumed)` narrowing-so-far, used to compute its current cell bounds.
+    /// `soft_cap` bounds the exact pass: once `out` reaches it, the current
+    /// node stops subdividing and emits one over-covering range instead, so
+    /// coverage is never dropped even on a region too large to fully resolve.
+    #[allow(clippy::type_complexity)]
+    fn recurse(
+        dims: &[Dim; Self::PREFIX_BITS],
+        pos: usize,
+        t: (u64, u32, u32),
+        lon: (u64, u32, u32),
+        lat: (u64, u32, u32),
+        query_t: (u64, u64),
+        query_lon: (u64, u64),
+        query_lat: (u64, u64),
+        prefix: &mut [PrefixBit; Self::PREFIX_BITS],
+        out: &mut Vec<(GeoTemporalUuid, GeoTemporalUuid)>,
+        soft_cap: usize,
+    ) {
+        let cell_range = |value: (u64, u32, u32)| -> (u64, u64) {
+            let (prefix_val, bits_total, bits_consumed) = value;
+            let remaining = bits_total - bits_consumed;
+            let lo = prefix_val << remaining;
+            let hi = lo | ((1u64 << remaining) - 1);
+            (lo, hi)
+        };
+
+        let (t_lo, t_hi) = cell_range(t);
+        let (lon_lo, lon_hi) = cell_range(lon);
+        let (lat_lo, lat_hi) = cell_range(lat);
+
+        let overlaps = |(lo, hi): (u64, u64), (q_lo, q_hi): (u64, u64)| lo <= q_hi && hi >= q_lo;
+        let fully_covered = |(lo, hi): (u64, u64), (q_lo, q_hi): (u64, u64)| lo >= q_lo && hi <= q_hi;
+
+        if !overlaps((t_lo, t_hi), query_t)
+            || !overlaps((lon_lo, lon_hi), query_lon)
+            || !overlaps((lat_lo, lat_hi), query_lat)
+        {
+            return;
+        }
+
+        let t_full = fully_covered((t_lo, t_hi), query_t);
+        let lon_full = fully_covered((lon_lo, lon_hi), query_lon);
+        let lat_full = fully_covered((lat_lo, lat_hi), query_lat);
+
+        // All three dimensions are already within the query everywhere under
+        // this node: it collapses to a single exact range.
+        if (t_full && lon_full && lat_full) || pos == Self::PREFIX_BITS {
+            out.push(Self::leaf_range(prefix, pos));
+            return;
+        }
+
+        // The exact pass has produced enough leaves already: stop here and
+        // over-cover the rest of this subtree instead of subdividing it
+        // further, so a huge or boundary-misaligned query can't blow the
+        // exact pass up to hundreds of thousands of tiny ranges.
+        if out.len() >= soft_cap {
+            out.push(Self::leaf_range(prefix, pos));
+            return;
+        }
+
+        let active_dim_full = match dims[pos] {
+            Dim::Time => t_full,
+            Dim::Lon => lon_full,
+            Dim::Lat => lat_full,
+        };
+
+        if active_dim_full {
+            // This bit's dimension is already fully covered, so both of its
+            // values lead to the same covered range — don't branch, just
+            // leave the bit free and move on to the next dimension.
+            prefix[pos] = PrefixBit::Free;
+            Self::recurse(
+                dims, pos + 1,
+                t, lon, lat,
+                query_t, query_lon, query_lat,
+                prefix, out, soft_cap,
+            );
+            return;
+        }
+
+        for bit in [false, true] {
+            prefix[pos] = if bit { PrefixBit::One } else { PrefixBit::Zero };
+            let (next_t, next_lon, next_lat) = match dims[pos] {
+                Dim::Time => (
+                    (t.0 << 1 | bit as u64, t.1, t.2 + 1),
+                    lon,
+                    lat,
+                ),
+                Dim::Lon => (
+                    t,
+                    (lon.0 << 1 | bit as u64, lon.1, lon.2 + 1),
+                    lat,
+                ),
+                Dim::Lat => (
+                    t,
+                    lon,
+                    (lat.0 << 1 | bit as u64, lat.1, lat.2 + 1),
+                ),
+            };
+            Self::recurse(
+                dims, pos + 1,
+                next_t, next_lon, next_lat,
+                query_t, query_lon, query_lat,
+                prefix, out, soft_cap,
+            );
+        }
+    }
+
+    /// Builds the `[lo, hi]` byte range for a leaf: the first `pos` bits of
+    /// the interleaved payload follow `prefix` (fixed where a dimension was
+    /// still being narrowed, free where it was already fully covered), and
+    /// everything after — the rest of the T/O/L prefix plus the always-free
+    /// random suffix — ranges over its full span.
+    fn leaf_range(prefix: &[PrefixBit; Self::PREFIX_BITS], pos: usize) -> (GeoTemporalUuid, GeoTemporalUuid) {
+        let mut lo_payload = [false; 122];
+        let mut hi_payload = [false; 122];
+
+        for i in 0..pos {
+            let (lo_bit, hi_bit) = match prefix[i] {
+                PrefixBit::Zero => (false, false),
+                PrefixBit::One => (true, true),
+                PrefixBit::Free => (false, true),
+            };
+            lo_payload[i] = lo_bit;
+            hi_payload[i] = hi_bit;
+        }
+        for i in pos..122 {
+            lo_payload[i] = false;
+            hi_payload[i] = true;
+        }
+
+        let lo = GeoTemporalUuid(GeoTemporalUuid::pack_payload(&lo_payload, GeoTemporalUuid::VERSION_2D));
+        let hi = GeoTemporalUuid(GeoTemporalUuid::pack_payload(&hi_payload, GeoTemporalUuid::VERSION_2D));
+        (lo, hi)
+    }
 }
 
 impl std::str::FromStr for GeoTemporalUuid {
@@ -227,10 +1035,10 @@ mod tests {
     fn test_encode_decode() {
         let lat = 40.6892;
         let lon = -74.0445;
-        let uuid = GeoTemporalUuid::new(lat, lon, None).unwrap();
-        
-        let (d_lat, d_lon, _time) = uuid.decode();
-        
+        let uuid = GeoTemporalUuid::new(lat, lon, None, TimeScale::Utc).unwrap();
+
+        let (d_lat, d_lon, _time) = uuid.decode(TimeScale::Utc);
+
         // Check precision (approx 1e-5 degrees)
         assert!((lat - d_lat).abs() < 1e-5);
         assert!((lon - d_lon).abs() < 1e-5);
@@ -238,44 +1046,325 @@ mod tests {
 
     #[test]
     fn test_ordering() {
-        let u1 = GeoTemporalUuid::new(0.0, 0.0, Some(Utc.timestamp_millis_opt(1000).unwrap())).unwrap();
-        let u2 = GeoTemporalUuid::new(0.0, 0.0, Some(Utc.timestamp_millis_opt(2000).unwrap())).unwrap();
-        
+        let u1 = GeoTemporalUuid::new(0.0, 0.0, Some(Utc.timestamp_millis_opt(1000).unwrap()), TimeScale::Utc).unwrap();
+        let u2 = GeoTemporalUuid::new(0.0, 0.0, Some(Utc.timestamp_millis_opt(2000).unwrap()), TimeScale::Utc).unwrap();
+
         assert!(u1 < u2); // Time dominant
     }
+
+    #[test]
+    fn test_base32_roundtrip() {
+        let uuid = GeoTemporalUuid::new(40.6892, -74.0445, None, TimeScale::Utc).unwrap();
+        let b32 = uuid.to_base32();
+        assert_eq!(b32.len(), 26);
+        assert_eq!(GeoTemporalUuid::from_base32(&b32).unwrap(), uuid);
+    }
+
+    #[test]
+    fn test_base32_preserves_ordering() {
+        let u1 = GeoTemporalUuid::new(0.0, 0.0, Some(Utc.timestamp_millis_opt(1000).unwrap()), TimeScale::Utc).unwrap();
+        let u2 = GeoTemporalUuid::new(0.0, 0.0, Some(Utc.timestamp_millis_opt(2000).unwrap()), TimeScale::Utc).unwrap();
+
+        assert!(u1.to_base32() < u2.to_base32());
+    }
+
+    #[test]
+    fn test_decode_in_tz() {
+        let time = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let uuid = GeoTemporalUuid::new(40.6892, -74.0445, Some(time), TimeScale::Utc).unwrap();
+
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let (_lat, _lon, localized) = uuid.decode_in_tz(tz, TimeScale::Utc);
+
+        // Noon UTC on 2024-06-01 is 08:00 EDT (UTC-4 during daylight saving).
+        assert!(localized.starts_with("2024-06-01T08:00:00"));
+    }
+
+    #[test]
+    fn test_decode_in_tz_matches_decode_3d_for_a_3d_id() {
+        let time = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+        let uuid = GeoTemporalUuid::new_3d(40.6892, -74.0445, 10_500.0, Some(time), TimeScale::Utc).unwrap();
+
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let (lat, lon, localized) = uuid.decode_in_tz(tz, TimeScale::Utc);
+
+        let (expected_lat, expected_lon, _alt, expected_utc) = uuid.decode_3d(TimeScale::Utc).unwrap();
+        assert_eq!(lat, expected_lat);
+        assert_eq!(lon, expected_lon);
+        assert_eq!(localized, expected_utc.with_timezone(&tz).to_rfc3339());
+
+        // Noon UTC on 2024-06-01 is 08:00 EDT (UTC-4 during daylight saving).
+        assert!(localized.starts_with("2024-06-01T08:00:00"));
+    }
+
+    #[test]
+    fn test_uuid_crate_roundtrip() {
+        let uuid = GeoTemporalUuid::new(40.6892, -74.0445, None, TimeScale::Utc).unwrap();
+        let std_uuid = uuid.to_uuid();
+        assert_eq!(std_uuid.get_version_num(), 7);
+        assert_eq!(std_uuid.get_variant(), uuid::Variant::RFC4122);
+        assert_eq!(GeoTemporalUuid::try_from_uuid(std_uuid).unwrap(), uuid);
+    }
+
+    #[test]
+    fn test_try_from_uuid_rejects_foreign_uuid() {
+        let foreign = uuid::Uuid::new_v4();
+        assert!(GeoTemporalUuid::try_from_uuid(foreign).is_err());
+    }
+
+    #[test]
+    fn test_uuid_crate_roundtrip_3d() {
+        let uuid = GeoTemporalUuid::new_3d(40.6892, -74.0445, 10_500.0, None, TimeScale::Utc).unwrap();
+        let std_uuid = uuid.to_uuid();
+        assert_eq!(std_uuid.get_version_num(), 8);
+        assert_eq!(std_uuid.get_variant(), uuid::Variant::RFC4122);
+        let round_tripped = GeoTemporalUuid::try_from_uuid(std_uuid).unwrap();
+        assert_eq!(round_tripped, uuid);
+        assert!(round_tripped.is_3d());
+    }
+
+    #[test]
+    fn test_monotonic_generator_strictly_increasing_within_same_millisecond() {
+        let mut gen = MonotonicGenerator::new();
+        let t = Utc.timestamp_millis_opt(1_000).unwrap();
+
+        let first = gen.next(1.0, 2.0, Some(t), TimeScale::Utc).unwrap();
+        let second = gen.next(1.0, 2.0, Some(t), TimeScale::Utc).unwrap();
+        let third = gen.next(1.0, 2.0, Some(t), TimeScale::Utc).unwrap();
+
+        assert!(first < second);
+        assert!(second < third);
+    }
+
+    #[test]
+    fn test_monotonic_generator_overflow_errors() {
+        let mut gen = MonotonicGenerator::new();
+        let t = Utc.timestamp_millis_opt(1_000).unwrap();
+
+        // Seed the generator at the top of the random range so the very next
+        // call within the same millisecond overflows the 25-bit field.
+        gen.last = Some((1_000, (1 << GeoTemporalUuid::RAND_BITS) - 1));
+        assert!(gen.next(1.0, 2.0, Some(t), TimeScale::Utc).is_err());
+    }
+
+    #[test]
+    fn test_time_scale_roundtrip_across_a_leap_second_boundary() {
+        // 2015-07-01T00:00:00Z is exactly when the TAI-UTC offset stepped
+        // from 35s to 36s; encoding/decoding on TAI or GPS must still recover
+        // the original UTC instant precisely.
+        let time = Utc.timestamp_millis_opt(1_435_708_800_000).unwrap();
+
+        for scale in [TimeScale::Utc, TimeScale::Tai, TimeScale::Gps] {
+            let uuid = GeoTemporalUuid::new(10.0, 20.0, Some(time), scale).unwrap();
+            let (_lat, _lon, decoded) = uuid.decode(scale);
+            assert_eq!(decoded.timestamp_millis(), time.timestamp_millis());
+        }
+    }
+
+    #[test]
+    fn test_gps_offset_from_tai_is_19_seconds() {
+        let time = Utc.timestamp_millis_opt(1_600_000_000_000).unwrap();
+        let tai_ms = utc_ms_to_scale(time.timestamp_millis() as u64, TimeScale::Tai);
+        let gps_ms = utc_ms_to_scale(time.timestamp_millis() as u64, TimeScale::Gps);
+        assert_eq!(tai_ms - gps_ms, 19_000);
+    }
+
+    #[test]
+    fn test_base32_rejects_bad_input() {
+        assert!(GeoTemporalUuid::from_base32("too-short").is_err());
+        // 26 chars, but leading symbol 'Z' (value 31) overflows the 3-bit slot.
+        assert!(GeoTemporalUuid::from_base32("ZZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+    }
+
+    #[test]
+    fn test_3d_encode_decode() {
+        let lat = 40.6892;
+        let lon = -74.0445;
+        let alt = 10_500.0;
+        let uuid = GeoTemporalUuid::new_3d(lat, lon, alt, None, TimeScale::Utc).unwrap();
+
+        assert!(uuid.is_3d());
+        let (d_lat, d_lon, d_alt, _time) = uuid.decode_3d(TimeScale::Utc).unwrap();
+        assert!((lat - d_lat).abs() < 1e-5);
+        assert!((lon - d_lon).abs() < 1e-5);
+        assert!((alt - d_alt).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_2d_uuid_is_not_3d() {
+        let uuid = GeoTemporalUuid::new(0.0, 0.0, None, TimeScale::Utc).unwrap();
+        assert!(!uuid.is_3d());
+        assert!(uuid.decode_3d(TimeScale::Utc).is_err());
+    }
+
+    #[test]
+    fn test_3d_preserves_time_dominant_ordering() {
+        let u1 = GeoTemporalUuid::new_3d(0.0, 0.0, 0.0, Some(Utc.timestamp_millis_opt(1000).unwrap()), TimeScale::Utc).unwrap();
+        let u2 = GeoTemporalUuid::new_3d(0.0, 0.0, 0.0, Some(Utc.timestamp_millis_opt(2000).unwrap()), TimeScale::Utc).unwrap();
+        assert!(u1 < u2);
+    }
+
+    #[test]
+    fn test_range_query_contains_ids_inside_the_box() {
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_000).unwrap(),
+            Utc.timestamp_millis_opt(1_600_000_060_000).unwrap(),
+        );
+        let ranges = RangeQuery::ranges(
+            (40.0, 41.0), (-74.5, -73.5), time_range, TimeScale::Utc, 64,
+        );
+        assert!(!ranges.is_empty());
+
+        let inside = GeoTemporalUuid::new(
+            40.5, -74.0,
+            Some(Utc.timestamp_millis_opt(1_600_000_030_000).unwrap()),
+            TimeScale::Utc,
+        ).unwrap();
+
+        assert!(ranges.iter().any(|(lo, hi)| *lo <= inside && inside <= *hi));
+    }
+
+    #[test]
+    fn test_range_query_excludes_ids_outside_the_box() {
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_000).unwrap(),
+            Utc.timestamp_millis_opt(1_600_000_060_000).unwrap(),
+        );
+        let ranges = RangeQuery::ranges(
+            (40.0, 41.0), (-74.5, -73.5), time_range, TimeScale::Utc, 64,
+        );
+
+        let outside = GeoTemporalUuid::new(
+            10.0, 10.0,
+            Some(Utc.timestamp_millis_opt(1_600_000_030_000).unwrap()),
+            TimeScale::Utc,
+        ).unwrap();
+
+        assert!(!ranges.iter().any(|(lo, hi)| *lo <= outside && outside <= *hi));
+    }
+
+    #[test]
+    fn test_range_query_respects_max_ranges() {
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_000).unwrap(),
+            Utc.timestamp_millis_opt(1_600_003_600_000).unwrap(),
+        );
+        let ranges = RangeQuery::ranges(
+            (-90.0, 90.0), (-180.0, 180.0), time_range, TimeScale::Utc, 5,
+        );
+        assert!(ranges.len() <= 5);
+    }
+
+    #[test]
+    fn test_range_query_does_not_catastrophically_fragment() {
+        // A narrow 1deg x 1deg x 60s box used to blow up to tens of millions
+        // of ranges because already-resolved dimensions kept branching.
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_000).unwrap(),
+            Utc.timestamp_millis_opt(1_600_000_060_000).unwrap(),
+        );
+        let ranges = RangeQuery::ranges(
+            (40.0, 41.0), (-74.5, -73.5), time_range, TimeScale::Utc, 10_000,
+        );
+        assert!(ranges.len() < 1_000, "got {} ranges", ranges.len());
+    }
+
+    #[test]
+    fn test_range_query_near_global_misaligned_stays_bounded() {
+        // A huge, boundary-misaligned query used to produce ~200,000 exact
+        // leaves before `collapse_to_budget` could even start merging them,
+        // which made the merge itself prohibitively slow. `soft_cap` should
+        // keep the exact pass small regardless of how misaligned the query
+        // is, while still covering every point inside it.
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_013).unwrap(),
+            Utc.timestamp_millis_opt(1_600_999_999_987).unwrap(),
+        );
+        let ranges = RangeQuery::ranges(
+            (-89.999, 89.999), (-179.999, 179.999), time_range, TimeScale::Utc, 64,
+        );
+        assert!(ranges.len() <= 64);
+
+        let inside = GeoTemporalUuid::new(
+            12.3456,
+            -45.6789,
+            Some(Utc.timestamp_millis_opt(1_600_500_000_000).unwrap()),
+            TimeScale::Utc,
+        ).unwrap();
+        assert!(ranges.iter().any(|(lo, hi)| *lo <= inside && inside <= *hi));
+    }
+
+    #[test]
+    fn test_range_query_large_max_ranges_does_not_blow_up_the_merge() {
+        // soft_cap used to scale linearly with max_ranges with no ceiling,
+        // so a caller asking for a generous scan budget (a bulk-export
+        // planner, say) could still drive the exact pass — and then
+        // collapse_to_budget's quadratic merge — into the hundreds of
+        // thousands of leaves. SOFT_CAP_CEILING bounds both regardless of
+        // what max_ranges the caller passes.
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_013).unwrap(),
+            Utc.timestamp_millis_opt(1_600_999_999_987).unwrap(),
+        );
+        let ranges = RangeQuery::ranges(
+            (-89.999, 89.999), (-179.999, 179.999), time_range, TimeScale::Utc, 100_000,
+        );
+        assert!(ranges.len() <= RangeQuery::SOFT_CAP_CEILING);
+    }
+
+    #[test]
+    fn test_range_query_base32_matches_byte_ranges() {
+        let time_range = (
+            Utc.timestamp_millis_opt(1_600_000_000_000).unwrap(),
+            Utc.timestamp_millis_opt(1_600_000_060_000).unwrap(),
+        );
+        let byte_ranges = RangeQuery::ranges((40.0, 41.0), (-74.5, -73.5), time_range, TimeScale::Utc, 64);
+        let b32_ranges = RangeQuery::ranges_base32((40.0, 41.0), (-74.5, -73.5), time_range, TimeScale::Utc, 64);
+
+        assert_eq!(byte_ranges.len(), b32_ranges.len());
+        for ((lo, hi), (lo32, hi32)) in byte_ranges.iter().zip(b32_ranges.iter()) {
+            assert_eq!(lo.to_base32(), *lo32);
+            assert_eq!(hi.to_base32(), *hi32);
+        }
+    }
 }
 
 
 
-// WASM Interface
-#[wasm_bindgen]
-pub fn generate_uuid(lat: f64, lon: f64, time_input: JsValue) -> Result<String, String> {
-    let time = if time_input.is_null() || time_input.is_undefined() {
-        Utc::now()
+/// Parses the flexible `time_input` JS value (ms number, ms/ISO-8601 string,
+/// or null/undefined for now) shared by the WASM generate entry points.
+fn parse_time_input(time_input: &JsValue) -> Result<DateTime<Utc>, String> {
+    if time_input.is_null() || time_input.is_undefined() {
+        Ok(Utc::now())
     } else if let Some(ms) = time_input.as_f64() {
          let secs = (ms / 1000.0) as i64;
          let nsecs = ((ms % 1000.0) * 1_000_000.0) as u32;
-         Utc.timestamp_opt(secs, nsecs).unwrap()
+         Ok(Utc.timestamp_opt(secs, nsecs).unwrap())
     } else if let Some(s) = time_input.as_string() {
         if let Ok(ms) = s.parse::<i64>() {
-            Utc.timestamp_millis_opt(ms).unwrap()
+            Ok(Utc.timestamp_millis_opt(ms).unwrap())
         } else {
             DateTime::parse_from_rfc3339(&s)
                 .map(|dt| dt.with_timezone(&Utc))
-                .or_else(|_| {
-                     // Try other formats?
-                     // naive datetime + utc?
-                     // chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M:%S")
-                     //    .map(|dt| DateTime::<Utc>::from_utc(dt, Utc))
-                     Err("Invalid format")
-                })
-                .map_err(|_| "Invalid ISO timestamp format")?
+                .map_err(|_| "Invalid ISO timestamp format".to_string())
         }
     } else {
-        return Err("Invalid time argument. Expected number (ms), string (ISO/ms), null, or undefined.".to_string());
-    };
-    
-    let uuid = GeoTemporalUuid::new(lat, lon, Some(time)).map_err(|e| e.to_string())?;
+        Err("Invalid time argument. Expected number (ms), string (ISO/ms), null, or undefined.".to_string())
+    }
+}
+
+// WASM Interface
+#[wasm_bindgen]
+pub fn generate_uuid(lat: f64, lon: f64, time_input: JsValue) -> Result<String, String> {
+    let time = parse_time_input(&time_input)?;
+    let uuid = GeoTemporalUuid::new(lat, lon, Some(time), TimeScale::Utc).map_err(|e| e.to_string())?;
+    Ok(uuid.to_uuid_string())
+}
+
+#[wasm_bindgen]
+pub fn generate_uuid_3d(lat: f64, lon: f64, alt_m: f64, time_input: JsValue) -> Result<String, String> {
+    let time = parse_time_input(&time_input)?;
+    let uuid = GeoTemporalUuid::new_3d(lat, lon, alt_m, Some(time), TimeScale::Utc).map_err(|e| e.to_string())?;
     Ok(uuid.to_uuid_string())
 }
 
@@ -284,14 +1373,49 @@ pub fn decode_uuid(uuid_str: &str) -> Result<Box<[f64]>, String> {
     // Parse string manually since we don't have FromStr yet or helper
     // Easier to rely on hex parsing or implement logic.
     // Wait, we don't have a from_string method yet.
-    
+
     use std::str::FromStr;
     let uuid = GeoTemporalUuid::from_str(uuid_str)?;
-    
-    let (lat, lon, time) = uuid.decode();
+
+    let (lat, lon, time) = uuid.decode(TimeScale::Utc);
     let time_ms = time.timestamp_millis() as f64;
     
     // Return array: [lat, lon, time_ms]
     let res = Box::new([lat, lon, time_ms]);
     Ok(res)
 }
+
+#[wasm_bindgen]
+pub fn decode_uuid_3d(uuid_str: &str) -> Result<Box<[f64]>, String> {
+    use std::str::FromStr;
+    let uuid = GeoTemporalUuid::from_str(uuid_str)?;
+
+    let (lat, lon, alt, time) = uuid.decode_3d(TimeScale::Utc)?;
+    let time_ms = time.timestamp_millis() as f64;
+
+    // Return array: [lat, lon, alt, time_ms]
+    let res = Box::new([lat, lon, alt, time_ms]);
+    Ok(res)
+}
+
+#[wasm_bindgen]
+pub fn uuid_to_base32(uuid_str: &str) -> Result<String, String> {
+    use std::str::FromStr;
+    let uuid = GeoTemporalUuid::from_str(uuid_str)?;
+    Ok(uuid.to_base32())
+}
+
+#[wasm_bindgen]
+pub fn uuid_from_base32(base32_str: &str) -> Result<String, String> {
+    let uuid = GeoTemporalUuid::from_base32(base32_str)?;
+    Ok(uuid.to_uuid_string())
+}
+
+#[wasm_bindgen]
+pub fn decode_uuid_tz(uuid_str: &str, tz: &str) -> Result<String, String> {
+    use std::str::FromStr;
+    let uuid = GeoTemporalUuid::from_str(uuid_str)?;
+    let tz: chrono_tz::Tz = tz.parse().map_err(|_| format!("Unknown timezone: {tz}"))?;
+    let (_lat, _lon, localized) = uuid.decode_in_tz(tz, TimeScale::Utc);
+    Ok(localized)
+}
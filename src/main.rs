@@ -1,5 +1,5 @@
-use clap::{Parser, Subcommand};
-use geotemporal_uuid::GeoTemporalUuid;
+use clap::{Parser, Subcommand, ValueEnum};
+use geotemporal_uuid::{GeoTemporalUuid, TimeScale};
 use chrono::{Utc, TimeZone, DateTime};
 use std::str::FromStr;
 
@@ -11,6 +11,34 @@ struct Cli {
     command: Commands,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Dashed hex UUID form
+    Uuid,
+    /// 26-character Crockford Base32 (ULID-style) form
+    Base32,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Scale {
+    /// Civil UTC, which repeats/skips around leap seconds
+    Utc,
+    /// Continuous International Atomic Time
+    Tai,
+    /// Continuous GPS time (TAI - 19s)
+    Gps,
+}
+
+impl From<Scale> for TimeScale {
+    fn from(scale: Scale) -> Self {
+        match scale {
+            Scale::Utc => TimeScale::Utc,
+            Scale::Tai => TimeScale::Tai,
+            Scale::Gps => TimeScale::Gps,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Generate a new GeoTemporal UUID
@@ -18,27 +46,57 @@ enum Commands {
         /// Latitude (-90 to 90)
         #[arg(long)]
         lat: f64,
-        
+
         /// Longitude (-180 to 180)
         #[arg(long)]
         lon: f64,
-        
+
         /// Optional Timestamp (ms or ISO-8601). Defaults to now.
         #[arg(long)]
         time: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Uuid)]
+        format: Format,
+
+        /// Time scale the timestamp field is encoded on
+        #[arg(long, value_enum, default_value_t = Scale::Utc)]
+        scale: Scale,
+
+        /// Optional altitude in meters (-500 to 100000). When given, generates a 3D ID.
+        #[arg(long)]
+        alt: Option<f64>,
     },
-    /// Decode an existing UUID
+    /// Decode an existing UUID (accepts either the hex or Base32 form)
     Decode {
-        /// The UUID string
+        /// The UUID string (dashed hex or 26-character Base32)
         uuid: String,
+
+        /// Also show the timestamp localized to this IANA timezone (e.g. America/New_York)
+        #[arg(long)]
+        tz: Option<String>,
+
+        /// Time scale the timestamp field was encoded on
+        #[arg(long, value_enum, default_value_t = Scale::Utc)]
+        scale: Scale,
     },
 }
 
+/// Parses a UUID argument given in either the dashed hex form or the
+/// 26-character Crockford Base32 form, disambiguated by length.
+fn parse_uuid_arg(s: &str) -> Result<GeoTemporalUuid, String> {
+    if s.len() == 26 {
+        GeoTemporalUuid::from_base32(s)
+    } else {
+        GeoTemporalUuid::from_str(s)
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Generate { lat, lon, time } => {
+        Commands::Generate { lat, lon, time, format, scale, alt } => {
             let dt = if let Some(t_str) = time {
                 if let Ok(ms) = t_str.parse::<i64>() {
                     Utc.timestamp_millis_opt(ms).unwrap()
@@ -51,19 +109,52 @@ fn main() {
                 Utc::now()
             };
 
-            match GeoTemporalUuid::new(lat, lon, Some(dt)) {
-                Ok(uuid) => println!("{}", uuid),
+            let result = match alt {
+                Some(alt_m) => GeoTemporalUuid::new_3d(lat, lon, alt_m, Some(dt), scale.into()),
+                None => GeoTemporalUuid::new(lat, lon, Some(dt), scale.into()),
+            };
+
+            match result {
+                Ok(uuid) => match format {
+                    Format::Uuid => println!("{}", uuid),
+                    Format::Base32 => println!("{}", uuid.to_base32()),
+                },
                 Err(e) => eprintln!("Error: {}", e),
             }
         },
-        Commands::Decode { uuid } => {
-             match GeoTemporalUuid::from_str(&uuid) {
+        Commands::Decode { uuid, tz, scale } => {
+             match parse_uuid_arg(&uuid) {
                 Ok(u) => {
-                    let (lat, lon, time) = u.decode();
-                    println!("UUID: {}", u);
+                    let scale: TimeScale = scale.into();
+                    println!("UUID:   {}", u);
+                    println!("Base32: {}", u.to_base32());
+
+                    let time = if u.is_3d() {
+                        let (lat, lon, alt, time) = u.decode_3d(scale).unwrap();
+                        println!("Lat:  {:.6}", lat);
+                        println!("Lon:  {:.6}", lon);
+                        println!("Alt:  {:.2} m", alt);
+                        time
+                    } else {
+                        let (lat, lon, time) = u.decode(scale);
+                        println!("Lat:  {:.6}", lat);
+                        println!("Lon:  {:.6}", lon);
+                        time
+                    };
                     println!("Time: {} ({})", time, time.timestamp_millis());
-                    println!("Lat:  {:.6}", lat);
-                    println!("Lon:  {:.6}", lon);
+
+                    if let Some(tz_name) = tz {
+                        match tz_name.parse::<chrono_tz::Tz>() {
+                            Ok(tz) => {
+                                // decode_in_tz dispatches to decode_3d internally for a
+                                // 3D id, so it doesn't need to sit inside the is_3d()
+                                // split above.
+                                let (_, _, localized) = u.decode_in_tz(tz, scale);
+                                println!("Time ({}): {}", tz_name, localized);
+                            }
+                            Err(_) => eprintln!("Unknown timezone: {}", tz_name),
+                        }
+                    }
                 },
                 Err(e) => eprintln!("Error decoding: {}", e),
              }